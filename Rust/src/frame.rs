@@ -0,0 +1,243 @@
+//! Self-describing frame format.
+//!
+//! Layered on top of the bare `LZ77R` token encoding, a framed stream uses
+//! the magic `LZ77F` and partitions the input into independently-compressed
+//! blocks, each prefixed with its compressed byte length and suffixed with a
+//! CRC32 of its *uncompressed* bytes. A zero-length block marks end-of-stream.
+//! This gives callers detectable corruption (per block) and the ability to
+//! concatenate or resume at block boundaries, at the cost of resetting the
+//! match window at every block.
+
+use std::io::{self, Write};
+
+use crate::lz77;
+
+/// Magic bytes identifying a framed LZ77 stream, as opposed to the bare
+/// format's `LZ77R`.
+pub const LZ77_FRAME_MAGIC: &[u8; 5] = b"LZ77F";
+
+// -----------------------------------------------------------------------------
+// CRC32 (IEEE 802.3 polynomial, reflected)
+// -----------------------------------------------------------------------------
+
+/// Computes the standard CRC32 (polynomial 0xEDB88320) of `data`.
+fn crc32(data: &[u8]) -> u32 {
+    const POLY: u32 = 0xEDB8_8320;
+    let mut crc = 0xFFFF_FFFFu32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLY & mask);
+        }
+    }
+
+    !crc
+}
+
+// -----------------------------------------------------------------------------
+// Frame Header
+// -----------------------------------------------------------------------------
+
+/// Writes the frame header: same field layout as the bare format's header
+/// (`lz77::LZ77_HEADER_LEN` bytes), but under the `LZ77F` magic.
+fn write_frame_header(out: &mut impl Write, window_size: usize, max_match_len: usize) -> io::Result<u64> {
+    out.write_all(LZ77_FRAME_MAGIC)?;
+    out.write_all(&(window_size as u16).to_le_bytes())?;
+    out.write_all(&(max_match_len as u16).to_le_bytes())?;
+    Ok(lz77::LZ77_HEADER_LEN as u64)
+}
+
+/// Validates the frame magic; the `window_size`/`max_match_len` fields are
+/// per-block advertisements only, since each block is compressed
+/// independently and may in principle use different parameters.
+fn read_frame_header(input: &[u8]) -> io::Result<()> {
+    if input.len() < lz77::LZ77_HEADER_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "input is too short to contain a valid LZ77F frame header",
+        ));
+    }
+    if &input[0..5] != LZ77_FRAME_MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing or invalid LZ77F magic bytes",
+        ));
+    }
+    Ok(())
+}
+
+// -----------------------------------------------------------------------------
+// Block Framing
+// -----------------------------------------------------------------------------
+
+/// Compression knobs shared by [`write_block`], [`compress_bytes_framed`],
+/// and [`compress_bytes_parallel`], bundled so those functions don't each
+/// need a long, error-prone list of positional arguments.
+#[derive(Clone, Copy)]
+pub struct FrameConfig {
+    pub window_size: usize,
+    pub max_match_len: usize,
+    pub max_chain_len: usize,
+    pub block_size: usize,
+    pub lazy: bool,
+}
+
+/// Compresses `chunk` as a single block and writes it as
+/// `[len:u32_le][compressed bytes][crc32:u32_le]`, where the CRC32 covers
+/// `chunk` itself (the uncompressed bytes), not the compressed bytes.
+pub fn write_block(out: &mut impl Write, chunk: &[u8], config: &FrameConfig) -> io::Result<u64> {
+    let mut compressed = Vec::new();
+    lz77::compress(
+        chunk,
+        &mut compressed,
+        config.window_size,
+        config.max_match_len,
+        config.max_chain_len,
+        config.lazy,
+    )?;
+
+    out.write_all(&(compressed.len() as u32).to_le_bytes())?;
+    out.write_all(&compressed)?;
+    out.write_all(&crc32(chunk).to_le_bytes())?;
+
+    Ok(4 + compressed.len() as u64 + 4)
+}
+
+/// Writes the zero-length block that marks end-of-stream.
+pub fn write_end_marker(out: &mut impl Write) -> io::Result<u64> {
+    out.write_all(&0u32.to_le_bytes())?;
+    Ok(4)
+}
+
+// -----------------------------------------------------------------------------
+// Public API
+// -----------------------------------------------------------------------------
+
+/// Compresses `input` into the framed LZ77 format: a frame header followed
+/// by `input` split into `block_size`-byte blocks, each compressed and
+/// checksummed independently, terminated by a zero-length block.
+///
+/// Blocks never reference across their boundary (each is matched only
+/// against its own bytes), which costs some ratio versus one continuous
+/// window but lets blocks be verified, concatenated, or decoded independently.
+pub fn compress_bytes_framed(
+    input: &[u8],
+    out: &mut impl Write,
+    config: &FrameConfig,
+) -> io::Result<u64> {
+    let mut bytes_written = write_frame_header(out, config.window_size, config.max_match_len)?;
+
+    for chunk in input.chunks(config.block_size.max(1)) {
+        bytes_written += write_block(out, chunk, config)?;
+    }
+    bytes_written += write_end_marker(out)?;
+
+    Ok(bytes_written)
+}
+
+/// Splits `input` into fixed `block_size` blocks, as [`compress_bytes_framed`]
+/// does, then compresses those blocks concurrently across up to
+/// `num_threads` worker threads before writing them out in original order.
+///
+/// Block boundaries come only from `block_size`, never from `num_threads` —
+/// threads are just a worker pool that processes a fixed partition of the
+/// input, each handling a contiguous group of blocks — so the compressed
+/// output is byte-for-byte deterministic regardless of how many threads are
+/// used to produce it. `num_threads` is a pure speed knob.
+pub fn compress_bytes_parallel(
+    input: &[u8],
+    out: &mut impl Write,
+    config: &FrameConfig,
+    num_threads: usize,
+) -> io::Result<u64> {
+    let mut bytes_written = write_frame_header(out, config.window_size, config.max_match_len)?;
+
+    let chunks: Vec<&[u8]> = input.chunks(config.block_size.max(1)).collect();
+    let num_threads = num_threads.max(1).min(chunks.len().max(1));
+    let chunks_per_thread = chunks.len().div_ceil(num_threads).max(1);
+
+    let groups = std::thread::scope(|scope| -> io::Result<Vec<Vec<Vec<u8>>>> {
+        let mut handles = Vec::new();
+        for group in chunks.chunks(chunks_per_thread) {
+            handles.push(scope.spawn(move || -> io::Result<Vec<Vec<u8>>> {
+                let mut blocks = Vec::with_capacity(group.len());
+                for chunk in group {
+                    let mut block = Vec::new();
+                    write_block(&mut block, chunk, config)?;
+                    blocks.push(block);
+                }
+                Ok(blocks)
+            }));
+        }
+
+        let mut groups = Vec::with_capacity(handles.len());
+        for handle in handles {
+            let blocks = handle
+                .join()
+                .map_err(|_| io::Error::other("compression worker thread panicked"))??;
+            groups.push(blocks);
+        }
+        Ok(groups)
+    })?;
+
+    for block in groups.into_iter().flatten() {
+        bytes_written += block.len() as u64;
+        out.write_all(&block)?;
+    }
+    bytes_written += write_end_marker(out)?;
+
+    Ok(bytes_written)
+}
+
+/// Reverses [`compress_bytes_framed`]: validates the frame header, then
+/// reads and decompresses each block in turn, verifying its CRC32 before
+/// writing its bytes to `out`.
+///
+/// Returns an error if the magic is missing, a block is truncated, or a
+/// block's checksum doesn't match its decoded bytes.
+pub fn decompress_bytes_framed(input: &[u8], out: &mut impl Write) -> io::Result<u64> {
+    read_frame_header(input)?;
+
+    let mut pos = lz77::LZ77_HEADER_LEN;
+    let mut total: u64 = 0;
+
+    loop {
+        let block_len = input.get(pos..pos + 4).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "truncated frame: missing block length")
+        })?;
+        let block_len = u32::from_le_bytes(block_len.try_into().unwrap()) as usize;
+        pos += 4;
+
+        if block_len == 0 {
+            break;
+        }
+
+        let compressed = input.get(pos..pos + block_len).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "truncated frame: missing block body")
+        })?;
+        pos += block_len;
+
+        let expected_crc = input.get(pos..pos + 4).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "truncated frame: missing block checksum")
+        })?;
+        let expected_crc = u32::from_le_bytes(expected_crc.try_into().unwrap());
+        pos += 4;
+
+        let mut block_out = Vec::new();
+        lz77::decompress(compressed, &mut block_out)?;
+
+        if crc32(&block_out) != expected_crc {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "block checksum mismatch: frame is corrupt",
+            ));
+        }
+
+        out.write_all(&block_out)?;
+        total += block_out.len() as u64;
+    }
+
+    Ok(total)
+}
@@ -1,6 +1,8 @@
 //! lz77_r - A Simple LZ77 Compressor Library
 
+pub mod frame;
 pub mod lz77;
 
 // Re-export main functions for convenience
-pub use lz77::{compress_str, compress_bytes};
+pub use lz77::{compress_str, compress_bytes, decompress_bytes, decompress_to_vec, Lz77Encoder};
+pub use frame::{compress_bytes_framed, compress_bytes_parallel};
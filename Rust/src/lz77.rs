@@ -17,21 +17,51 @@ const LZ77_TOKEN_LITERAL_MAGIC: u8 = 0x00;
 /// Token type magic byte for a reference.
 const LZ77_TOKEN_REFERENCE_MAGIC: u8 =  0x01;
 
+/// Total size of the header written by [`write_header`]: 5-byte magic plus
+/// two `u16` little-endian fields. Shared with the frame format, whose
+/// header has the same field layout under a different magic.
+pub(crate) const LZ77_HEADER_LEN: usize = 9;
+
 // -----------------------------------------------------------------------------
 // Header and Token Emission
 // -----------------------------------------------------------------------------
 
 /// Writes the file header.
 ///
-/// Format (10 bytes total):
-/// - Bytes 0-5: Magic "LZ77R1"
-/// - Bytes 6-7: window_size as u16 little-endian
-/// - Bytes 8-9: max_match_len as u16 little-endian
-fn write_header(out: &mut impl Write, window_szie: usize, max_match_len: usize) -> io::Result<u64> {
+/// Format (9 bytes total):
+/// - Bytes 0-4: Magic "LZ77R"
+/// - Bytes 5-6: window_size as u16 little-endian
+/// - Bytes 7-8: max_match_len as u16 little-endian
+fn write_header(out: &mut impl Write, window_size: usize, max_match_len: usize) -> io::Result<u64> {
     out.write_all(LZ77_MAGIC)?;
-    out.write_all(&window_szie.to_le_bytes())?;
-    out.write_all(&max_match_len.to_le_bytes())?;
-    Ok(10)
+    out.write_all(&(window_size as u16).to_le_bytes())?;
+    out.write_all(&(max_match_len as u16).to_le_bytes())?;
+    Ok(LZ77_HEADER_LEN as u64)
+}
+
+/// Reads and validates the header, returning `(window_size, max_match_len)`.
+///
+/// The caller is expected to have already sliced off any bytes before the
+/// header; on success the token stream begins at `input[LZ77_HEADER_LEN..]`.
+fn read_header(input: &[u8]) -> io::Result<(usize, usize)> {
+    if input.len() < LZ77_HEADER_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "input is too short to contain a valid LZ77R header",
+        ));
+    }
+
+    if &input[0..5] != LZ77_MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing or invalid LZ77R magic bytes",
+        ));
+    }
+
+    let window_size = u16::from_le_bytes([input[5], input[6]]) as usize;
+    let max_match_len = u16::from_le_bytes([input[7], input[8]]) as usize;
+
+    Ok((window_size, max_match_len))
 }
 
 /// Emits a 2-byte literal token: [0x00][byte_value]
@@ -54,6 +84,10 @@ fn emit_reference_token(out: &mut impl Write, offset: u16, length: u16) -> io::R
 // Match Finding
 // -----------------------------------------------------------------------------
 
+/// Number of bits in the hash-chain table index, i.e. the table holds
+/// `1 << HASH_BITS` buckets.
+const HASH_BITS: u32 = 15;
+
 /// Computes how many bytes match between two positions in the input.
 #[inline]
 fn compute_match_length(
@@ -72,63 +106,187 @@ fn compute_match_length(
     length
 }
 
-/// Searches backward in the sliding window for the longest match.
+/// Index over `input` mapping the hash of every 3-byte sequence to its most
+/// recent occurrence, with a linked list (`prev`) threading together earlier
+/// occurrences that hashed to the same bucket.
 ///
-/// This is a naive O(window_size) search for each position. It scans every
-/// position in the window and keeps track of the longest match found.
-///
-/// # Returns
-/// (offset, length) where offset is the distance backward from `pos`.
-/// Returns (0, 0) if no match of at least MIN_MATCH_LEN is found.
-fn find_longest_match(
-    input: &[u8],
-    pos: usize,
-    window_size: usize,
-    max_match_len: usize,
-) -> (usize, usize) {
-    let mut best_offset: usize = 0;
-    let mut best_length: usize = 0;
-
-    // Window spans from max(0, pos - window_size) to pos (exclusive)
-    let window_start = pos.saturating_sub(window_size);
-
-    // Try each candidate position in the window
-    for candidate in window_start..pos {
-        let length = compute_match_length(input, candidate, pos, max_match_len);
-        if length > best_length {
-            best_length = length;
-            best_offset = pos - candidate;
+/// This replaces a naive O(window_size) rescan at every position with a
+/// bounded walk of at most `max_chain_len` prior occurrences. That bound
+/// trades ratio for speed: on positions with longer hash chains than
+/// `max_chain_len`, the match returned can be shorter than the true longest
+/// match a brute-force scan of the window would have found.
+struct HashChain {
+    /// `head[h]` is the most recently inserted position whose 3-byte hash is
+    /// `h`, or `-1` if none has been inserted yet.
+    head: Vec<i32>,
+    /// `prev[pos]` is the previous position with the same hash as `pos`, or
+    /// `-1` if `pos` is the first occurrence of its hash.
+    prev: Vec<i32>,
+    /// Upper bound on how many candidates to follow down a chain before
+    /// giving up on finding a longer match.
+    max_chain_len: usize,
+}
+
+impl HashChain {
+    fn new(max_chain_len: usize) -> Self {
+        Self {
+            head: vec![-1; 1 << HASH_BITS],
+            prev: Vec::new(),
+            max_chain_len,
+        }
+    }
+
+    /// Multiplicative hash of the 3-byte sequence starting at `pos`, folded
+    /// down to `HASH_BITS` bits.
+    #[inline]
+    fn hash3(input: &[u8], pos: usize) -> usize {
+        let sequence = (input[pos] as u32)
+            | (input[pos + 1] as u32) << 8
+            | (input[pos + 2] as u32) << 16;
+        ((sequence.wrapping_mul(2_654_435_761)) >> (32 - HASH_BITS)) as usize
+    }
+
+    /// Records `pos` as the most recent occurrence of the 3-byte sequence
+    /// starting there.
+    ///
+    /// `pos` must equal `self.prev.len()`: positions are inserted strictly in
+    /// order, one `prev` slot per byte, so that later indices stay aligned
+    /// with buffer positions even when fewer than 3 bytes remain to hash.
+    fn insert(&mut self, input: &[u8], pos: usize) {
+        debug_assert_eq!(pos, self.prev.len());
+        if pos + LZ77_MIN_MATCH_LEN > input.len() {
+            self.prev.push(-1);
+            return;
         }
+        let h = Self::hash3(input, pos);
+        self.prev.push(self.head[h]);
+        self.head[h] = pos as i32;
     }
 
-    (best_offset, best_length)
+    /// Rebases the chain after `drop` leading bytes are evicted from the
+    /// front of the buffer: every recorded position shifts down by `drop`,
+    /// and any position that would go negative (it pointed into the evicted
+    /// prefix) is cleared to `-1`.
+    fn slide(&mut self, drop: usize) {
+        let drop = drop as i32;
+        for slot in self.head.iter_mut() {
+            *slot = if *slot >= drop { *slot - drop } else { -1 };
+        }
+        self.prev.drain(0..drop as usize);
+        for slot in self.prev.iter_mut() {
+            *slot = if *slot >= drop { *slot - drop } else { -1 };
+        }
+    }
+
+    /// Searches the hash chain for the longest match at `pos`.
+    ///
+    /// Walks backward through positions sharing `pos`'s 3-byte hash, stopping
+    /// once a candidate falls outside `pos - window_size` or `max_chain_len`
+    /// candidates have been tried.
+    ///
+    /// # Returns
+    /// (offset, length) where offset is the distance backward from `pos`.
+    /// Returns (0, 0) if no match of at least LZ77_MIN_MATCH_LEN is found.
+    fn find_longest_match(
+        &self,
+        input: &[u8],
+        pos: usize,
+        window_size: usize,
+        max_match_len: usize,
+    ) -> (usize, usize) {
+        if pos + LZ77_MIN_MATCH_LEN > input.len() {
+            return (0, 0);
+        }
+
+        let window_start = pos.saturating_sub(window_size);
+        let h = Self::hash3(input, pos);
+
+        let mut best_offset: usize = 0;
+        let mut best_length: usize = 0;
+        let mut candidate = self.head[h];
+        let mut steps = 0;
+
+        while candidate >= 0 && (candidate as usize) >= window_start && steps < self.max_chain_len {
+            let candidate_pos = candidate as usize;
+            let length = compute_match_length(input, candidate_pos, pos, max_match_len);
+            if length > best_length {
+                best_length = length;
+                best_offset = pos - candidate_pos;
+            }
+            candidate = self.prev[candidate_pos];
+            steps += 1;
+        }
+
+        (best_offset, best_length)
+    }
 }
 
 // -----------------------------------------------------------------------------
 // Core Compression Logic
 // -----------------------------------------------------------------------------
 
-fn compress(
+/// Compresses `input` into a bare token stream (no header), used both by the
+/// bare public entry points below and, per-block, by the frame format.
+///
+/// When `lazy` is set, the match found at each position is compared against
+/// the match found one byte ahead before committing to it: if the latter is
+/// strictly longer, the current byte is emitted as a literal and the search
+/// defers to the longer match (the classic deflate "lazy matching"
+/// heuristic). This only changes which tokens are emitted, not how the
+/// decompressor reads them.
+pub(crate) fn compress(
     input: &[u8],
     out: &mut impl Write,
     window_size: usize,
     max_match_len: usize,
+    max_chain_len: usize,
+    lazy: bool,
 ) -> io::Result<u64> {
     let mut bytes_written: u64 = 0;
     let mut pos: usize = 0;
+    let mut hash_chain = HashChain::new(max_chain_len);
+    // When lazy matching defers, the lookahead match it already computed for
+    // `pos + 1` becomes the current match next iteration; caching it here
+    // avoids walking the hash chain for the same position twice.
+    let mut pending_match: Option<(usize, usize)> = None;
 
     while pos < input.len() {
-        let (match_offset, match_length) = find_longest_match(input, pos, window_size, max_match_len);
+        let (match_offset, match_length) = match pending_match.take() {
+            Some(m) => m,
+            None => hash_chain.find_longest_match(input, pos, window_size, max_match_len),
+        };
         if match_length >= LZ77_MIN_MATCH_LEN {
+            hash_chain.insert(input, pos);
+
+            if lazy && pos + 1 < input.len() {
+                let next_match =
+                    hash_chain.find_longest_match(input, pos + 1, window_size, max_match_len);
+                if next_match.1 > match_length {
+                    // A longer match starts one byte ahead; defer to it.
+                    bytes_written += emit_literal_token(out, input[pos])?;
+                    pos += 1;
+                    pending_match = Some(next_match);
+                    continue;
+                }
+            }
+
             bytes_written += emit_reference_token(out, match_offset as u16, match_length as u16)?;
-            pos += match_length;
+
+            let match_end = pos + match_length;
+            pos += 1; // already inserted above
+            while pos < match_end {
+                hash_chain.insert(input, pos);
+                pos += 1;
+            }
 
             // Classic LZ77: emit the next byte as a literal (if any remain)
             if pos < input.len() {
+                hash_chain.insert(input, pos);
                 bytes_written += emit_literal_token(out, input[pos])?;
                 pos += 1;
             }
         } else {
+            hash_chain.insert(input, pos);
             bytes_written += emit_literal_token(out, input[pos])?;
             pos += 1;
         }
@@ -137,19 +295,95 @@ fn compress(
     Ok(bytes_written)
 }
 
+// -----------------------------------------------------------------------------
+// Core Decompression Logic
+// -----------------------------------------------------------------------------
+
+/// Walks the token stream, expanding literals and references into `output`.
+///
+/// References are expanded byte-by-byte rather than with a bulk slice copy so
+/// that overlapping matches (offset shorter than length, e.g. run-length
+/// expansion) resolve correctly: each copied byte becomes visible to the
+/// copy that follows it.
+///
+/// Used both by the bare format and, per-block, by the frame format.
+pub(crate) fn decompress(tokens: &[u8], out: &mut impl Write) -> io::Result<u64> {
+    let mut output: Vec<u8> = Vec::new();
+    let mut pos: usize = 0;
+
+    while pos < tokens.len() {
+        match tokens[pos] {
+            LZ77_TOKEN_LITERAL_MAGIC => {
+                let literal = *tokens.get(pos + 1).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "truncated literal token")
+                })?;
+                output.push(literal);
+                pos += 2;
+            }
+
+            LZ77_TOKEN_REFERENCE_MAGIC => {
+                if pos + 5 > tokens.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "truncated reference token",
+                    ));
+                }
+                let offset = u16::from_le_bytes([tokens[pos + 1], tokens[pos + 2]]) as usize;
+                let length = u16::from_le_bytes([tokens[pos + 3], tokens[pos + 4]]) as usize;
+                pos += 5;
+
+                if offset == 0 || offset > output.len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "reference offset points before the start of output",
+                    ));
+                }
+
+                let start = output.len() - offset;
+                for i in 0..length {
+                    let byte = output[start + i];
+                    output.push(byte);
+                }
+            }
+
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown token tag: 0x{:02x}", other),
+                ));
+            }
+        }
+    }
+
+    out.write_all(&output)?;
+    Ok(output.len() as u64)
+}
+
 // -----------------------------------------------------------------------------
 // Public API
 // -----------------------------------------------------------------------------
 
+/// Compresses `input` into the LZ77R format and writes it to `out`.
+///
+/// `max_chain_len` bounds how many prior occurrences of a position's 3-byte
+/// hash the hash-chain matcher will follow before settling for the best
+/// match found so far. Higher values can find longer matches (better ratio)
+/// at the cost of more time spent per position; lower values compress
+/// faster at some cost to ratio.
+///
+/// `lazy` enables one-step-ahead lazy matching; see [`compress`] for what it
+/// trades off.
 pub fn compress_bytes(
     input: &[u8],
     out: &mut impl Write,
     window_size: usize,
     max_match_len: usize,
+    max_chain_len: usize,
+    lazy: bool,
 ) -> io::Result<u64> {
     let mut bytes_written: u64 = 0;
     bytes_written += write_header(out, window_size, max_match_len)?;
-    bytes_written += compress(input, out, window_size, max_match_len)?;
+    bytes_written += compress(input, out, window_size, max_match_len, max_chain_len, lazy)?;
     Ok(bytes_written)
 }
 
@@ -158,6 +392,291 @@ pub fn compress_str(
     out: &mut impl Write,
     window_size: usize,
     max_match_len: usize,
+    max_chain_len: usize,
+    lazy: bool,
 ) -> io::Result<u64> {
-    compress(s.as_bytes(), out, window_size, max_match_len)
+    compress(s.as_bytes(), out, window_size, max_match_len, max_chain_len, lazy)
+}
+
+// -----------------------------------------------------------------------------
+// Streaming API
+// -----------------------------------------------------------------------------
+
+/// Default `max_chain_len` used by [`Lz77Encoder::new`]; see
+/// [`compress_bytes`] for what the tunable trades off.
+const DEFAULT_MAX_CHAIN_LEN: usize = 128;
+
+/// Incremental encoder for input that arrives in chunks rather than as one
+/// `&[u8]` slice, so arbitrarily large inputs can be compressed in constant
+/// memory. Internally it keeps a rolling buffer of at most `window_size`
+/// trailing processed bytes plus whatever has arrived but not yet been
+/// tokenized; positions older than that are evicted and the hash chain is
+/// rebased to match, so memory stays bounded regardless of total input size.
+pub struct Lz77Encoder<W: Write> {
+    out: W,
+    window_size: usize,
+    max_match_len: usize,
+    lazy: bool,
+    hash_chain: HashChain,
+    buffer: Vec<u8>,
+    pos: usize,
+    bytes_written: u64,
+    /// Cached lookahead match for `pos`, already computed by a prior
+    /// deferral decision; see [`compress`] for why this avoids a repeat
+    /// hash-chain walk.
+    pending_match: Option<(usize, usize)>,
+}
+
+impl<W: Write> Lz77Encoder<W> {
+    /// Creates an encoder that writes the LZ77R header to `out` immediately,
+    /// then streams tokens for each chunk passed to [`Self::write`].
+    pub fn new(out: W, window_size: usize, max_match_len: usize) -> io::Result<Self> {
+        Self::with_max_chain_len(out, window_size, max_match_len, DEFAULT_MAX_CHAIN_LEN, false)
+    }
+
+    /// Like [`Self::new`], but with an explicit `max_chain_len` (see
+    /// [`compress_bytes`] for what it trades off) and `lazy` flag (see
+    /// [`compress`] for what one-step-ahead lazy matching trades off).
+    pub fn with_max_chain_len(
+        mut out: W,
+        window_size: usize,
+        max_match_len: usize,
+        max_chain_len: usize,
+        lazy: bool,
+    ) -> io::Result<Self> {
+        let bytes_written = write_header(&mut out, window_size, max_match_len)?;
+        Ok(Self {
+            out,
+            window_size,
+            max_match_len,
+            lazy,
+            hash_chain: HashChain::new(max_chain_len),
+            buffer: Vec::new(),
+            pos: 0,
+            bytes_written,
+            pending_match: None,
+        })
+    }
+
+    /// Feeds the next chunk of input into the encoder, emitting tokens for
+    /// as much of the buffered data as can be matched with full lookahead.
+    ///
+    /// The trailing `max_match_len - 1` bytes of what's buffered (or, in lazy
+    /// mode, `max_match_len` bytes, since deciding whether to defer also
+    /// needs full lookahead one byte ahead) are held back until more data
+    /// arrives (or [`Self::flush`]/[`Self::finish`] is called), since a match
+    /// ending at the very end of the buffer might extend further once the
+    /// next chunk is seen.
+    pub fn write(&mut self, chunk: &[u8]) -> io::Result<()> {
+        self.buffer.extend_from_slice(chunk);
+        let reserve = if self.lazy {
+            self.max_match_len
+        } else {
+            self.max_match_len.saturating_sub(1)
+        };
+        self.process(reserve)?;
+        self.slide_window();
+        Ok(())
+    }
+
+    /// Tokenizes every byte currently buffered, including the lookahead
+    /// reserve, so the token stream is complete up to a resumable boundary.
+    /// More data may still be written afterward.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.process(0)?;
+        self.out.flush()?;
+        self.slide_window();
+        Ok(())
+    }
+
+    /// Flushes any remaining buffered bytes and returns the total number of
+    /// bytes written (header plus tokens), consuming the encoder.
+    pub fn finish(mut self) -> io::Result<u64> {
+        self.flush()?;
+        Ok(self.bytes_written)
+    }
+
+    /// Emits tokens for buffered positions until no more than `reserve` bytes
+    /// remain unprocessed ahead of `self.pos`.
+    fn process(&mut self, reserve: usize) -> io::Result<()> {
+        while self.buffer.len() - self.pos > reserve {
+            let (offset, length) = match self.pending_match.take() {
+                Some(m) => m,
+                None => self.hash_chain.find_longest_match(
+                    &self.buffer,
+                    self.pos,
+                    self.window_size,
+                    self.max_match_len,
+                ),
+            };
+
+            if length >= LZ77_MIN_MATCH_LEN {
+                self.hash_chain.insert(&self.buffer, self.pos);
+
+                if self.lazy && self.pos + 1 < self.buffer.len() {
+                    let next_match = self.hash_chain.find_longest_match(
+                        &self.buffer,
+                        self.pos + 1,
+                        self.window_size,
+                        self.max_match_len,
+                    );
+                    if next_match.1 > length {
+                        self.bytes_written +=
+                            emit_literal_token(&mut self.out, self.buffer[self.pos])?;
+                        self.pos += 1;
+                        self.pending_match = Some(next_match);
+                        continue;
+                    }
+                }
+
+                self.bytes_written +=
+                    emit_reference_token(&mut self.out, offset as u16, length as u16)?;
+
+                let match_end = self.pos + length;
+                self.pos += 1; // already inserted above
+                while self.pos < match_end {
+                    self.hash_chain.insert(&self.buffer, self.pos);
+                    self.pos += 1;
+                }
+
+                if self.buffer.len() - self.pos > reserve {
+                    self.hash_chain.insert(&self.buffer, self.pos);
+                    self.bytes_written += emit_literal_token(&mut self.out, self.buffer[self.pos])?;
+                    self.pos += 1;
+                }
+            } else {
+                self.hash_chain.insert(&self.buffer, self.pos);
+                self.bytes_written += emit_literal_token(&mut self.out, self.buffer[self.pos])?;
+                self.pos += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evicts buffered bytes older than `window_size` behind `self.pos`,
+    /// keeping memory use bounded regardless of how much input has been fed
+    /// in so far.
+    fn slide_window(&mut self) {
+        let drop = self.pos.saturating_sub(self.window_size);
+        if drop == 0 {
+            return;
+        }
+        self.buffer.drain(0..drop);
+        self.pos -= drop;
+        self.hash_chain.slide(drop);
+    }
+}
+
+/// Reverses [`compress_bytes`] or [`crate::frame::compress_bytes_framed`],
+/// auto-detecting which by the 5-byte magic and expanding the stream back
+/// into the original bytes, writing them to `out`.
+///
+/// Returns an error if the magic is missing or unrecognized, the input is
+/// truncated, a token tag is unrecognized, a reference offset points before
+/// the start of the decoded output, or (for a framed stream) a block's
+/// checksum doesn't match its decoded bytes.
+pub fn decompress_bytes(input: &[u8], out: &mut impl Write) -> io::Result<u64> {
+    if input.len() >= 5 && &input[0..5] == crate::frame::LZ77_FRAME_MAGIC {
+        return crate::frame::decompress_bytes_framed(input, out);
+    }
+    let (_window_size, _max_match_len) = read_header(input)?;
+    decompress(&input[LZ77_HEADER_LEN..], out)
+}
+
+/// Convenience wrapper around [`decompress_bytes`] that returns the
+/// decompressed bytes as a `Vec<u8>` instead of writing to a `Write`.
+pub fn decompress_to_vec(input: &[u8]) -> io::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    decompress_bytes(input, &mut out)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_input() -> Vec<u8> {
+        b"the quick brown fox jumps over the lazy dog. "
+            .repeat(20)
+    }
+
+    #[test]
+    fn bare_round_trip() {
+        let input = sample_input();
+        let mut compressed = Vec::new();
+        compress_bytes(&input, &mut compressed, 4096, 258, 128, false).unwrap();
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn lazy_round_trip() {
+        let input = sample_input();
+        let mut compressed = Vec::new();
+        compress_bytes(&input, &mut compressed, 4096, 258, 128, true).unwrap();
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn streaming_round_trip() {
+        let input = sample_input();
+        let mut compressed = Vec::new();
+        {
+            let mut encoder =
+                Lz77Encoder::with_max_chain_len(&mut compressed, 256, 32, 128, false).unwrap();
+            for chunk in input.chunks(17) {
+                encoder.write(chunk).unwrap();
+            }
+            encoder.finish().unwrap();
+        }
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), input);
+    }
+
+    /// Feeds tiny chunks (well under `max_match_len`) through a lazy-mode
+    /// encoder so both the larger lookahead reserve and the `pending_match`
+    /// deferral carry correctly across repeated `slide_window` calls.
+    #[test]
+    fn streaming_lazy_round_trip() {
+        let input = sample_input();
+        let mut compressed = Vec::new();
+        {
+            let mut encoder =
+                Lz77Encoder::with_max_chain_len(&mut compressed, 256, 32, 128, true).unwrap();
+            for chunk in input.chunks(5) {
+                encoder.write(chunk).unwrap();
+            }
+            encoder.finish().unwrap();
+        }
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn framed_round_trip() {
+        let input = sample_input();
+        let mut compressed = Vec::new();
+        let config = crate::frame::FrameConfig {
+            window_size: 4096,
+            max_match_len: 258,
+            max_chain_len: 128,
+            block_size: 64,
+            lazy: false,
+        };
+        crate::frame::compress_bytes_framed(&input, &mut compressed, &config).unwrap();
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn parallel_round_trip() {
+        let input = sample_input();
+        let mut compressed = Vec::new();
+        let config = crate::frame::FrameConfig {
+            window_size: 4096,
+            max_match_len: 258,
+            max_chain_len: 128,
+            block_size: 64,
+            lazy: false,
+        };
+        crate::frame::compress_bytes_parallel(&input, &mut compressed, &config, 4).unwrap();
+        assert_eq!(decompress_to_vec(&compressed).unwrap(), input);
+    }
 }
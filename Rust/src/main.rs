@@ -10,10 +10,16 @@ use std::{env, io};
 use std::fs::File;
 use std::process;
 
-use lz77r::lz77;
+use lz77r::{frame, lz77};
 
 const DEFAULT_LZ77_WINDOW_SIZE: u16 = 4096;
 const DEFAULT_LZ77_MAX_MATCH_LEN: u16 = 258;
+const DEFAULT_LZ77_MAX_CHAIN_LEN: u16 = 128;
+const DEFAULT_LZ77_BLOCK_SIZE: u32 = 64 * 1024;
+
+/// Chunk size used to feed the streaming encoder, so compression runs in
+/// constant memory regardless of the size of the input file or stdin stream.
+const READ_BLOCK_SIZE: usize = 64 * 1024;
 
 // -----------------------------------------------------------------------------
 // Argument Parsing
@@ -26,6 +32,12 @@ struct Args {
     output_filename: Option<String>,
     window_size: u16,
     max_match_len: u16,
+    max_chain_len: u16,
+    framed: bool,
+    block_size: u32,
+    num_threads: u32,
+    decompress: bool,
+    lazy: bool,
     show_help: bool,
 }
 
@@ -37,6 +49,12 @@ impl Default for Args {
             output_filename: None,
             window_size: DEFAULT_LZ77_WINDOW_SIZE,
             max_match_len: DEFAULT_LZ77_MAX_MATCH_LEN,
+            max_chain_len: DEFAULT_LZ77_MAX_CHAIN_LEN,
+            framed: false,
+            block_size: DEFAULT_LZ77_BLOCK_SIZE,
+            num_threads: 1,
+            decompress: false,
+            lazy: false,
             show_help: false,
         }
     }
@@ -54,6 +72,14 @@ fn parse_args() -> Result<Args, String> {
                 return Ok(args);
             }
 
+            "-d" | "--decompress" => {
+                args.decompress = true;
+            }
+
+            "-l" | "--lazy" => {
+                args.lazy = true;
+            }
+
             "-f" => {
                 i += 1;
                 if i >= argv.len() {
@@ -98,6 +124,41 @@ fn parse_args() -> Result<Args, String> {
                     .map_err(|_| format!("Invalid max match length: '{}'", argv[i]))?;
             }
 
+            "-c" => {
+                i += 1;
+                if i >= argv.len() {
+                    return Err("-c requires a numeric argument".into());
+                }
+                args.max_chain_len = argv[i]
+                    .parse()
+                    .map_err(|_| format!("Invalid max chain length: '{}'", argv[i]))?;
+            }
+
+            "-F" | "--framed" => {
+                args.framed = true;
+            }
+
+            "-b" => {
+                i += 1;
+                if i >= argv.len() {
+                    return Err("-b requires a numeric argument".into());
+                }
+                args.block_size = argv[i]
+                    .parse()
+                    .map_err(|_| format!("Invalid block size: '{}'", argv[i]))?;
+            }
+
+            "-j" => {
+                i += 1;
+                if i >= argv.len() {
+                    return Err("-j requires a numeric argument".into());
+                }
+                args.num_threads = argv[i]
+                    .parse()
+                    .map_err(|_| format!("Invalid thread count: '{}'", argv[i]))?;
+                args.framed = true;
+            }
+
             other => {
                 return Err(format!("Unknown argument: '{}'", other));
             }
@@ -129,6 +190,18 @@ OPTIONS:
     -o <path>     Write output to file (default: stdout)
     -w <size>     Sliding window size in bytes (default: 4096)
     -m <length>   Maximum match length in bytes (default: 258)
+    -c <length>   Max hash-chain candidates to try per position (default: 128)
+    -l, --lazy    Lazy matching: defer to a longer match found one byte
+                  ahead instead of greedily taking the current one
+    -F, --framed  Use the self-describing block-framed format (LZ77F), with
+                  a per-block CRC32 so corruption can be detected
+    -b <size>     Block size in bytes when -F or -j is used (default: 65536)
+    -j <n>        Compress the fixed-size blocks (see -b) across n worker
+                  threads (implies the framed format; default: 1). Output
+                  is identical regardless of n; it only affects speed.
+    -d, --decompress
+                  Decompress input instead of compressing it (format is
+                  auto-detected, bare or framed)
     -h, --help    Show this help message
 
 INPUT:
@@ -136,8 +209,8 @@ INPUT:
     You cannot specify both -f and -s simultaneously.
 
 OUTPUT FORMAT:
-    Header (10 bytes):
-        - Magic: "LZ77R1" (6 bytes)
+    Bare (default), header (9 bytes):
+        - Magic: "LZ77R" (5 bytes)
         - Window size: u16 little-endian (2 bytes)
         - Max match length: u16 little-endian (2 bytes)
 
@@ -145,11 +218,17 @@ OUTPUT FORMAT:
         - Literal: 0x00 <byte>
         - Match:   0x01 <offset:u16_le> <length:u16_le>
 
+    Framed (-F), header (9 bytes, same layout with magic "LZ77F"), followed
+    by a sequence of blocks and a zero-length block marking end-of-stream:
+        - Block: <len:u32_le> <tokens: len bytes> <crc32:u32_le>
+
 EXAMPLES:
     lz77r -f input.bin > output.lz77
     lz77r -f input.bin -o output.lz77
     lz77r -s "hello hello hello" -o hello.lz77
     echo "test data" | lz77r > test.lz77
+    lz77r -F -f input.bin -o output.lz77
+    lz77r -d -f output.lz77 -o restored.bin
 "#;
     print!("{}", help);
 }
@@ -170,6 +249,43 @@ fn read_input(args: &Args) -> io::Result<Vec<u8>> {
     }
 }
 
+/// Feeds `args`'s input source through `encoder` in fixed-size blocks
+/// instead of reading it fully into memory, returning the total bytes read.
+fn feed_input(args: &Args, encoder: &mut lz77::Lz77Encoder<Box<dyn Write>>) -> io::Result<usize> {
+    let mut input_size = 0;
+    let mut block = [0u8; READ_BLOCK_SIZE];
+
+    if let Some(ref s) = args.input_string {
+        let bytes = s.as_bytes();
+        encoder.write(bytes)?;
+        input_size = bytes.len();
+    } else if let Some(ref path) = args.input_filename {
+        let mut file = File::open(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("Cannot open '{}': {}", path, e)))?;
+        loop {
+            let n = file.read(&mut block)?;
+            if n == 0 {
+                break;
+            }
+            encoder.write(&block[..n])?;
+            input_size += n;
+        }
+    } else {
+        let stdin = io::stdin();
+        let mut handle = stdin.lock();
+        loop {
+            let n = handle.read(&mut block)?;
+            if n == 0 {
+                break;
+            }
+            encoder.write(&block[..n])?;
+            input_size += n;
+        }
+    }
+
+    Ok(input_size)
+}
+
 fn create_output(args: &Args) -> io::Result<Box<dyn Write>> {
     match &args.output_filename {
         Some(path) => {
@@ -184,23 +300,73 @@ fn create_output(args: &Args) -> io::Result<Box<dyn Write>> {
 }
 
 fn run(args: &Args) -> io::Result<()> {
-    let input_data = read_input(args)?;
-    let input_size = input_data.len();
-
-    let mut output = create_output(args)?;
-
-    let bytes_written = lz77::compress_bytes(
-        &input_data,
-        &mut output,
-        args.window_size as usize,
-        args.max_match_len as usize,
-    )?;
-
-    output.flush()?;
+    let (input_size, bytes_written) = if args.decompress {
+        let input_data = read_input(args)?;
+        let input_size = input_data.len();
+
+        let mut output = create_output(args)?;
+        let bytes_written = lz77::decompress_bytes(&input_data, &mut output)?;
+        output.flush()?;
+
+        (input_size, bytes_written)
+    } else if args.num_threads > 1 {
+        let input_data = read_input(args)?;
+        let input_size = input_data.len();
+
+        let mut output = create_output(args)?;
+        let config = frame::FrameConfig {
+            window_size: args.window_size as usize,
+            max_match_len: args.max_match_len as usize,
+            max_chain_len: args.max_chain_len as usize,
+            block_size: args.block_size as usize,
+            lazy: args.lazy,
+        };
+        let bytes_written = frame::compress_bytes_parallel(
+            &input_data,
+            &mut output,
+            &config,
+            args.num_threads as usize,
+        )?;
+        output.flush()?;
+
+        (input_size, bytes_written)
+    } else if args.framed {
+        let input_data = read_input(args)?;
+        let input_size = input_data.len();
+
+        let mut output = create_output(args)?;
+        let config = frame::FrameConfig {
+            window_size: args.window_size as usize,
+            max_match_len: args.max_match_len as usize,
+            max_chain_len: args.max_chain_len as usize,
+            block_size: args.block_size as usize,
+            lazy: args.lazy,
+        };
+        let bytes_written = frame::compress_bytes_framed(&input_data, &mut output, &config)?;
+        output.flush()?;
+
+        (input_size, bytes_written)
+    } else {
+        let output = create_output(args)?;
+        let mut encoder = lz77::Lz77Encoder::with_max_chain_len(
+            output,
+            args.window_size as usize,
+            args.max_match_len as usize,
+            args.max_chain_len as usize,
+            args.lazy,
+        )?;
+
+        let input_size = feed_input(args, &mut encoder)?;
+        let bytes_written = encoder.finish()?;
+
+        (input_size, bytes_written)
+    };
 
     if args.output_filename.is_some() {
+        let verb = if args.decompress { "Decompressed" } else { "Compressed" };
         eprintln!(
-            "Compressed {} bytes -> {} bytes ({:.1}%)",
+            "{} {} bytes -> {} bytes ({:.1}%)",
+            verb,
             input_size,
             bytes_written,
             if input_size > 0 {